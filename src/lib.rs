@@ -29,35 +29,217 @@ fn square(phase: f64) -> f64 {
     }
 }
 
+//
+// harmonic spectra, used to build band-limited tables
+//
+// each function returns the amplitude of the waveform's k-th harmonic
+// (k starting at 1) in its ideal Fourier series.
+//
+
+fn sine_harmonic(k: usize) -> f64 {
+    if k == 1 {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn tri_harmonic(k: usize) -> f64 {
+    if k % 2 == 1 {
+        let sign = if (k / 2) % 2 == 1 { -1.0 } else { 1.0 };
+        sign / (k * k) as f64
+    } else {
+        0.0
+    }
+}
+
+fn saw_harmonic(k: usize) -> f64 {
+    1.0 / k as f64
+}
+
+fn square_harmonic(k: usize) -> f64 {
+    if k % 2 == 1 {
+        1.0 / k as f64
+    } else {
+        0.0
+    }
+}
+
+// Sums a harmonic series into a single-cycle table of `size` samples,
+// including only the harmonics that stay under Nyquist for notes played up
+// to `f_max`. The fundamental (`k == 1`) is always kept, even when `f_max` is
+// pinned to exactly Nyquist, so the topmost band never ends up silent.
+fn band_limited_table(size: usize, fs: f64, f_max: f64, harmonic: fn(usize) -> f64) -> Vec<f64> {
+    let nyquist = fs / 2.0;
+    let mut table = vec![0.0; size];
+    let mut k = 1;
+    while k == 1 || (k as f64) * f_max < nyquist {
+        let amp = harmonic(k);
+        if amp != 0.0 {
+            for (i, sample) in table.iter_mut().enumerate() {
+                *sample += amp * (2.0 * PI * k as f64 * i as f64 / size as f64).sin();
+            }
+        }
+        k += 1;
+    }
+    table
+}
+
+// One band per octave, from 20 Hz up to the Nyquist frequency of `fs`.
+fn octave_band_maxes(fs: f64) -> Vec<f64> {
+    let nyquist = fs / 2.0;
+    let mut maxes = Vec::new();
+    let mut f = 20.0;
+    while f < nyquist {
+        maxes.push(f);
+        f *= 2.0;
+    }
+    maxes.push(nyquist);
+    maxes
+}
+
+struct BandTable {
+    f_max: f64,
+    table: Vec<f64>,
+}
+
 //
 // wavetable definition
 //
 
 pub trait WaveTable {
     fn new(size: usize) -> Self;
-    fn synth(&self, n: usize, f: f64, fs: f64) -> f64;
+    fn synth(&self, n: usize, f: f64, fs: SamplingRate) -> f64;
+    /// Reads the table at an arbitrary `phase` in `[0, 1)`, picking whichever
+    /// band covers frequency `f` when the table is band-limited. This is what
+    /// `synth` is built on, and what `Oscillator` uses to read from a running
+    /// phase accumulator instead of an absolute sample index.
+    fn sample_at(&self, phase: f64, f: f64) -> f64;
+}
+
+/// Selects how a table is read between its sample points. Defaults to
+/// `Linear`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Two-point linear interpolation. Cheap, but leaves audible high-frequency
+    /// error at small table sizes.
+    #[default]
+    Linear,
+    /// 4-point cubic Hermite interpolation. Costs a few more cycles per sample
+    /// in exchange for much cleaner output at small table sizes.
+    Cubic,
+}
+
+// Linearly interpolates `table` at fractional position `phase * table.len()`.
+fn lerp_table(table: &[f64], phase: f64) -> f64 {
+    let size = table.len();
+    let pos = phase * size as f64;
+    let i = pos as usize;
+    let t = pos - pos.floor();
+    (1.0 - t) * table[i] + t * table[(i + 1) % size]
+}
+
+// 4-point cubic Hermite interpolation of `table` at fractional position
+// `phase * table.len()`, reading one point before and two points after the
+// enclosing pair (indices taken modulo `size`).
+fn cubic_table(table: &[f64], phase: f64) -> f64 {
+    let size = table.len();
+    let pos = phase * size as f64;
+    let i = pos.floor() as isize;
+    let t = pos - pos.floor();
+
+    let at = |offset: isize| -> f64 {
+        let index = (i + offset).rem_euclid(size as isize) as usize;
+        table[index]
+    };
+    let y0 = at(-1);
+    let y1 = at(0);
+    let y2 = at(1);
+    let y3 = at(2);
+
+    let m0 = (y2 - y0) / 2.0;
+    let m1 = (y3 - y1) / 2.0;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+    let h10 = t3 - 2.0 * t2 + t;
+    let h01 = -2.0 * t3 + 3.0 * t2;
+    let h11 = t3 - t2;
+
+    h00 * y1 + h10 * m0 + h01 * y2 + h11 * m1
+}
+
+fn interpolate(table: &[f64], phase: f64, interp: Interpolation) -> f64 {
+    match interp {
+        Interpolation::Linear => lerp_table(table, phase),
+        Interpolation::Cubic => cubic_table(table, phase),
+    }
 }
 
 macro_rules! impl_wavetable {
     ($($waveform:ident)+) => {
         $(
             pub struct $waveform {
-                size: usize,
                 table: Vec<f64>,
+                bands: Vec<BandTable>,
+                interp: Interpolation,
             }
 
             impl WaveTable for $waveform {
                 fn new(size: usize) -> Self {
                     paste! {
                         let table: Vec<f64> = (0..size).map(|i| [<$waveform:lower>](i as f64 / size as f64)).collect();
-                        $waveform { size, table }
+                        $waveform { table, bands: Vec::new(), interp: Interpolation::default() }
                     }
                 }
 
-                fn synth(&self, n: usize, f: f64, fs: f64) -> f64 {
-                    let pos = (n as f64 * f / fs).fract() * self.size as f64;
-                    let rel_pos = pos / self.size as f64;
-                    (1.0 - rel_pos) * self.table[pos as usize] + rel_pos * self.table[(pos as usize + 1) % self.size]
+                fn synth(&self, n: usize, f: f64, fs: SamplingRate) -> f64 {
+                    self.sample_at((n as f64 * f / fs.get()).fract(), f)
+                }
+
+                fn sample_at(&self, phase: f64, f: f64) -> f64 {
+                    interpolate(self.active_table(f), phase, self.interp)
+                }
+            }
+
+            impl $waveform {
+                /// Builds one band-limited table per octave, each containing only the
+                /// harmonics that stay under the Nyquist frequency of `fs` for notes up
+                /// to that band's top frequency. `synth` then picks the band that just
+                /// covers the requested frequency, which keeps high-pitched `Saw` and
+                /// `Square` alias-free.
+                pub fn new_bandlimited(size: usize, fs: SamplingRate) -> Self {
+                    paste! {
+                        // `octave_band_maxes` always yields at least the Nyquist band, so
+                        // `bands` is never empty and `active_table` never falls back to
+                        // `table` here; no need to build the non-band-limited table too.
+                        let bands = octave_band_maxes(fs.get())
+                            .into_iter()
+                            .map(|f_max| BandTable {
+                                f_max,
+                                table: band_limited_table(size, fs.get(), f_max, [<$waveform:lower _harmonic>]),
+                            })
+                            .collect();
+                        $waveform { table: Vec::new(), bands, interp: Interpolation::default() }
+                    }
+                }
+
+                /// Switches which [`Interpolation`] this waveform's table is read with.
+                pub fn with_interpolation(mut self, interp: Interpolation) -> Self {
+                    self.interp = interp;
+                    self
+                }
+
+                fn active_table(&self, f: f64) -> &Vec<f64> {
+                    if self.bands.is_empty() {
+                        &self.table
+                    } else {
+                        &self.bands
+                            .iter()
+                            .find(|band| band.f_max >= f)
+                            .unwrap_or_else(|| self.bands.last().unwrap())
+                            .table
+                    }
                 }
             }
         )*
@@ -66,16 +248,263 @@ macro_rules! impl_wavetable {
 
 impl_wavetable!{ Sine Tri Saw Square }
 
+//
+// additive synthesis from an arbitrary harmonic spectrum
+//
+
+/// One partial in a harmonic spectrum: amplitude `amp` and phase `phase`
+/// (radians) of the `n`-th harmonic above the fundamental.
+pub struct Harmonic {
+    pub n: usize,
+    pub amp: f64,
+    pub phase: f64,
+}
+
+/// A single-cycle table built by summing an arbitrary list of harmonics,
+/// rather than one of the fixed waveform shapes above. This is how `Saw`,
+/// `Square` and friends could themselves be expressed (a saw is `1/n` on
+/// every harmonic, a square is `1/n` on odd harmonics only), but exposed so
+/// callers can design their own timbres.
+pub struct Additive {
+    table: Vec<f64>,
+    interp: Interpolation,
+}
+
+impl WaveTable for Additive {
+    fn new(size: usize) -> Self {
+        Additive { table: vec![0.0; size], interp: Interpolation::default() }
+    }
+
+    fn synth(&self, n: usize, f: f64, fs: SamplingRate) -> f64 {
+        self.sample_at((n as f64 * f / fs.get()).fract(), f)
+    }
+
+    fn sample_at(&self, phase: f64, _f: f64) -> f64 {
+        interpolate(&self.table, phase, self.interp)
+    }
+}
+
+impl Additive {
+    /// Builds a table by summing `amp * sin(2π * n * i/size + phase)` for each
+    /// harmonic over the table, then normalizes the result to peak 1.0.
+    pub fn from_harmonics(size: usize, harmonics: &[Harmonic]) -> Self {
+        let mut table = vec![0.0; size];
+        for h in harmonics {
+            for (i, sample) in table.iter_mut().enumerate() {
+                *sample += h.amp * (2.0 * PI * h.n as f64 * i as f64 / size as f64 + h.phase).sin();
+            }
+        }
+        let peak = table.iter().fold(0.0_f64, |max, &x| max.max(x.abs()));
+        if peak > 0.0 {
+            for sample in table.iter_mut() {
+                *sample /= peak;
+            }
+        }
+        Additive { table, interp: Interpolation::default() }
+    }
+
+    /// Switches which [`Interpolation`] this harmonic series' table is read with.
+    pub fn with_interpolation(mut self, interp: Interpolation) -> Self {
+        self.interp = interp;
+        self
+    }
+}
+
+//
+// signal sources
+//
+
+/// A signal source that produces one sample per call, advancing whatever
+/// internal state it keeps. `Oscillator` and the noise generators below both
+/// implement this, so downstream code can pull from any of them uniformly.
+pub trait Source {
+    fn next(&mut self, fs: SamplingRate) -> f64;
+}
+
+//
+// stateful oscillator
+//
+
+/// A wavetable oscillator that holds its own running phase, so frequency can
+/// change from sample to sample without the phase jump `WaveTable::synth`
+/// would otherwise introduce.
+pub struct Oscillator<W: WaveTable> {
+    table: W,
+    phase: f64,
+    freq: f64,
+}
+
+impl<W: WaveTable> Oscillator<W> {
+    pub fn new(table: W) -> Self {
+        Oscillator { table, phase: 0.0, freq: 0.0 }
+    }
+
+    /// Sets the frequency `next` (the `Source` impl) reads at.
+    pub fn set_freq(&mut self, f: f64) {
+        self.freq = f;
+    }
+
+    /// Reads the current phase, then advances the phase accumulator by `f/fs`.
+    pub fn next_sample(&mut self, f: f64, fs: SamplingRate) -> f64 {
+        let sample = self.table.sample_at(self.phase, f);
+        self.phase = (self.phase + f / fs.get()).rem_euclid(1.0);
+        sample
+    }
+
+    /// Phase-modulation FM: reads the table at the carrier phase offset by
+    /// `fm_index * fm_input`, then advances the carrier phase by `carrier_f/fs`.
+    /// Feeding another oscillator's output in as `fm_input` modulates this one.
+    pub fn next_sample_fm(
+        &mut self,
+        carrier_f: f64,
+        fm_input: f64,
+        fm_index: f64,
+        fs: SamplingRate,
+    ) -> f64 {
+        let modulated_phase = (self.phase + fm_index * fm_input).rem_euclid(1.0);
+        let sample = self.table.sample_at(modulated_phase, carrier_f);
+        self.phase = (self.phase + carrier_f / fs.get()).rem_euclid(1.0);
+        sample
+    }
+
+    /// Streams samples at a fixed `f`/`fs` as a standard `Iterator`, so callers
+    /// can write `osc.iter(freq, fs).take(n).collect()` instead of looping over
+    /// sample indices by hand. The iterator is infinite.
+    pub fn iter(&mut self, f: f64, fs: SamplingRate) -> OscillatorIter<'_, W> {
+        OscillatorIter { osc: self, f, fs }
+    }
+}
+
+impl<W: WaveTable> Source for Oscillator<W> {
+    /// Equivalent to `next_sample(self.freq, fs)`, using whatever frequency
+    /// was last passed to [`Oscillator::set_freq`].
+    fn next(&mut self, fs: SamplingRate) -> f64 {
+        self.next_sample(self.freq, fs)
+    }
+}
+
+/// The infinite iterator returned by [`Oscillator::iter`].
+pub struct OscillatorIter<'a, W: WaveTable> {
+    osc: &'a mut Oscillator<W>,
+    f: f64,
+    fs: SamplingRate,
+}
+
+impl<W: WaveTable> Iterator for OscillatorIter<'_, W> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        Some(self.osc.next_sample(self.f, self.fs))
+    }
+}
+
+//
+// sampling rate
+//
+
+/// A validated sample rate: finite and strictly positive. Raw `f64` values for
+/// `fs` can silently divide by zero or feed NaN into a phase accumulator;
+/// constructing a `SamplingRate` rules that out once, at the boundary.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SamplingRate(f64);
+
+impl SamplingRate {
+    pub fn get(&self) -> f64 {
+        self.0
+    }
+}
+
+/// The error returned when constructing a [`SamplingRate`] from a value that
+/// isn't finite and strictly positive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidSamplingRate(f64);
+
+impl std::fmt::Display for InvalidSamplingRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid sampling rate: {} (must be finite and > 0)", self.0)
+    }
+}
+
+impl std::error::Error for InvalidSamplingRate {}
+
+impl TryFrom<f64> for SamplingRate {
+    type Error = InvalidSamplingRate;
+
+    fn try_from(fs: f64) -> Result<Self, Self::Error> {
+        if fs.is_finite() && fs > 0.0 {
+            Ok(SamplingRate(fs))
+        } else {
+            Err(InvalidSamplingRate(fs))
+        }
+    }
+}
+
+//
+// noise sources
+//
+
+// A small xorshift64* PRNG, good enough for audio noise and with no
+// dependency beyond the standard library.
+fn xorshift64star(state: &mut u64) -> u64 {
+    let mut x = *state;
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    *state = x;
+    x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+}
+
+/// White noise: uniform samples in `[-1, 1]` from a seeded xorshift64* PRNG.
+pub struct White {
+    state: u64,
+}
+
+impl White {
+    /// A zero `seed` is replaced with a fixed nonzero constant, since xorshift
+    /// generators stay stuck at zero.
+    pub fn new(seed: u64) -> Self {
+        White { state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed } }
+    }
+}
+
+impl Source for White {
+    fn next(&mut self, _fs: SamplingRate) -> f64 {
+        let r = xorshift64star(&mut self.state);
+        (r as f64 / u64::MAX as f64) * 2.0 - 1.0
+    }
+}
+
+/// Brown noise: integrates white noise (`value += white * step`), with the
+/// running sum clamped to `[-1, 1]` so it can't wander off and stay pinned.
+pub struct Brown {
+    white: White,
+    value: f64,
+    step: f64,
+}
+
+impl Brown {
+    pub fn new(seed: u64) -> Self {
+        Brown { white: White::new(seed), value: 0.0, step: 0.02 }
+    }
+}
+
+impl Source for Brown {
+    fn next(&mut self, fs: SamplingRate) -> f64 {
+        self.value = (self.value + self.white.next(fs) * self.step).clamp(-1.0, 1.0);
+        self.value
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use hound;
     use dasp_sample::Sample;
 
-    fn write_wave(wt: &impl WaveTable, f: f64, fs: f64, dur_sec: f64, name: &str) {
+    fn write_wave(wt: &impl WaveTable, f: f64, fs: SamplingRate, dur_sec: f64, name: &str) {
         let spec = hound::WavSpec {
             channels: 1,
-            sample_rate: fs as u32,
+            sample_rate: fs.get() as u32,
             bits_per_sample: 16,
             sample_format: hound::SampleFormat::Int,
         };
@@ -88,24 +517,246 @@ mod tests {
     #[test]
     fn generate_sine_wave() {
         let wt = Sine::new(1024);
-        write_wave(&wt, 531.33, 44100.0, 1.0, "wav/sine_wavetable_C.wav");
+        write_wave(&wt, 531.33, SamplingRate::try_from(44100.0).unwrap(), 1.0, "wav/sine_wavetable_C.wav");
     }
 
     #[test]
     fn generate_tri_wave() {
         let wt = Tri::new(1024);
-        write_wave(&wt, 531.33, 44100.0, 1.0, "wav/tri_wavetable_C.wav");
+        write_wave(&wt, 531.33, SamplingRate::try_from(44100.0).unwrap(), 1.0, "wav/tri_wavetable_C.wav");
     }
 
     #[test]
     fn generate_saw_wave() {
         let wt = Saw::new(1024);
-        write_wave(&wt, 531.33, 44100.0, 1.0, "wav/saw_wavetable_C.wav");
+        write_wave(&wt, 531.33, SamplingRate::try_from(44100.0).unwrap(), 1.0, "wav/saw_wavetable_C.wav");
     }
 
     #[test]
     fn generate_square_wave() {
         let wt = Square::new(1024);
-        write_wave(&wt, 531.33, 44100.0, 1.0, "wav/square_wavetable_C.wav");
+        write_wave(&wt, 531.33, SamplingRate::try_from(44100.0).unwrap(), 1.0, "wav/square_wavetable_C.wav");
+    }
+
+    #[test]
+    fn generate_bandlimited_saw_wave() {
+        let wt = Saw::new_bandlimited(1024, SamplingRate::try_from(44100.0).unwrap());
+        write_wave(&wt, 3000.0, SamplingRate::try_from(44100.0).unwrap(), 1.0, "wav/saw_bandlimited_wavetable.wav");
+    }
+
+    #[test]
+    fn generate_additive_organ_wave() {
+        let harmonics = [
+            Harmonic { n: 1, amp: 1.0, phase: 0.0 },
+            Harmonic { n: 2, amp: 0.5, phase: 0.0 },
+            Harmonic { n: 3, amp: 0.25, phase: 0.0 },
+            Harmonic { n: 4, amp: 0.125, phase: 0.0 },
+        ];
+        let wt = Additive::from_harmonics(1024, &harmonics);
+        write_wave(&wt, 531.33, SamplingRate::try_from(44100.0).unwrap(), 1.0, "wav/additive_organ_wavetable.wav");
+    }
+
+    #[test]
+    fn additive_table_peaks_at_one() {
+        let harmonics = [
+            Harmonic { n: 1, amp: 1.0, phase: 0.0 },
+            Harmonic { n: 2, amp: 0.5, phase: 0.0 },
+        ];
+        let wt = Additive::from_harmonics(1024, &harmonics);
+        let peak = wt.table.iter().fold(0.0_f64, |max, &x| max.max(x.abs()));
+        assert!((peak - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn generate_fm_sine_wave() {
+        let fs = SamplingRate::try_from(44100.0).unwrap();
+        let mut modulator = Oscillator::new(Sine::new(1024));
+        let mut carrier = Oscillator::new(Sine::new(1024));
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: fs.get() as u32,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create("wav/fm_sine_wavetable.wav", spec).unwrap();
+        for _ in 0..(fs.get() * 1.0) as usize {
+            let modulator_out = modulator.next_sample(220.0, fs);
+            let sample = carrier.next_sample_fm(440.0, modulator_out, 2.0, fs);
+            writer.write_sample(sample.to_sample::<i16>()).unwrap();
+        }
+    }
+
+    #[test]
+    fn oscillator_phase_is_continuous_across_frequency_changes() {
+        let fs = SamplingRate::try_from(44100.0).unwrap();
+        let mut osc = Oscillator::new(Sine::new(1024));
+        let first = osc.next_sample(440.0, fs);
+        // A stateless synth(n, f, fs) call would jump to a different absolute
+        // phase here since f changed; the oscillator's running phase should
+        // only ever advance by the previous frequency's phase increment.
+        let second = osc.next_sample(880.0, fs);
+        let expected_phase = (440.0 / fs.get()).rem_euclid(1.0);
+        let expected = Sine::new(1024).sample_at(expected_phase, 880.0);
+        assert_eq!(first, Sine::new(1024).sample_at(0.0, 440.0));
+        assert!((second - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn oscillator_iter_matches_next_sample() {
+        let fs = SamplingRate::try_from(44100.0).unwrap();
+        let mut streamed = Oscillator::new(Sine::new(1024));
+        let samples: Vec<f64> = streamed.iter(440.0, fs).take(8).collect();
+
+        let mut stepped = Oscillator::new(Sine::new(1024));
+        let expected: Vec<f64> = (0..8).map(|_| stepped.next_sample(440.0, fs)).collect();
+
+        assert_eq!(samples, expected);
+    }
+
+    #[test]
+    fn sampling_rate_rejects_non_positive_and_non_finite_values() {
+        assert!(SamplingRate::try_from(44100.0).is_ok());
+        assert!(SamplingRate::try_from(0.0).is_err());
+        assert!(SamplingRate::try_from(-44100.0).is_err());
+        assert!(SamplingRate::try_from(f64::NAN).is_err());
+        assert!(SamplingRate::try_from(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn generate_white_noise_wave() {
+        let fs = SamplingRate::try_from(44100.0).unwrap();
+        let mut white = White::new(1);
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: fs.get() as u32,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create("wav/white_noise.wav", spec).unwrap();
+        for _ in 0..(fs.get() * 1.0) as usize {
+            writer.write_sample(white.next(fs).to_sample::<i16>()).unwrap();
+        }
+    }
+
+    #[test]
+    fn generate_brown_noise_wave() {
+        let fs = SamplingRate::try_from(44100.0).unwrap();
+        let mut brown = Brown::new(1);
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: fs.get() as u32,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create("wav/brown_noise.wav", spec).unwrap();
+        for _ in 0..(fs.get() * 1.0) as usize {
+            writer.write_sample(brown.next(fs).to_sample::<i16>()).unwrap();
+        }
+    }
+
+    #[test]
+    fn white_noise_stays_in_range() {
+        let fs = SamplingRate::try_from(44100.0).unwrap();
+        let mut white = White::new(42);
+        for _ in 0..10_000 {
+            let sample = white.next(fs);
+            assert!((-1.0..=1.0).contains(&sample));
+        }
+    }
+
+    #[test]
+    fn brown_noise_stays_in_range_and_is_smoother_than_white() {
+        let fs = SamplingRate::try_from(44100.0).unwrap();
+        let mut white = White::new(7);
+        let mut brown = Brown::new(7);
+        let mut white_total_jump = 0.0;
+        let mut brown_total_jump = 0.0;
+        let mut prev_white = white.next(fs);
+        let mut prev_brown = brown.next(fs);
+        for _ in 0..10_000 {
+            let w = white.next(fs);
+            let b = brown.next(fs);
+            assert!((-1.0..=1.0).contains(&b));
+            white_total_jump += (w - prev_white).abs();
+            brown_total_jump += (b - prev_brown).abs();
+            prev_white = w;
+            prev_brown = b;
+        }
+        assert!(brown_total_jump < white_total_jump);
+    }
+
+    #[test]
+    fn oscillator_as_source_uses_set_freq() {
+        let fs = SamplingRate::try_from(44100.0).unwrap();
+        let mut source_osc = Oscillator::new(Sine::new(1024));
+        source_osc.set_freq(440.0);
+        let mut direct_osc = Oscillator::new(Sine::new(1024));
+        for _ in 0..8 {
+            let via_source: f64 = Source::next(&mut source_osc, fs);
+            let direct = direct_osc.next_sample(440.0, fs);
+            assert_eq!(via_source, direct);
+        }
+    }
+
+    #[test]
+    fn bandlimited_saw_has_no_energy_above_nyquist() {
+        let fs = 44100.0;
+        let size = 1024;
+        let f_max = 200.0;
+        let table = band_limited_table(size, fs, f_max, saw_harmonic);
+        let nyquist = fs / 2.0;
+        let highest_included_k = ((nyquist / f_max).ceil() as usize).saturating_sub(1).max(1);
+
+        // The table is built purely from the included harmonics, and the sine
+        // basis is orthogonal over one cycle, so correlating against a harmonic
+        // that was excluded should yield ~0 energy.
+        for k in (highest_included_k + 1)..(highest_included_k + 20) {
+            let energy: f64 = table
+                .iter()
+                .enumerate()
+                .map(|(i, x)| x * (2.0 * PI * k as f64 * i as f64 / size as f64).sin())
+                .sum();
+            assert!(energy.abs() < 1e-9, "harmonic {} leaked energy: {}", k, energy);
+        }
+    }
+
+    #[test]
+    fn band_limited_table_keeps_fundamental_when_f_max_is_nyquist() {
+        let fs = 44100.0;
+        let size = 1024;
+        let f_max = fs / 2.0;
+        let table = band_limited_table(size, fs, f_max, saw_harmonic);
+
+        assert!(table.iter().any(|&x| x != 0.0), "topmost band is silent");
+    }
+
+    #[test]
+    fn cubic_interpolation_is_spectrally_cleaner_than_linear_at_small_table_size() {
+        let size = 32;
+        let n_probes = 10_000;
+
+        let linear = Sine::new(size);
+        let cubic = Sine::new(size).with_interpolation(Interpolation::Cubic);
+
+        // Spectral impurity: read one cycle at many sub-sample phases, project
+        // out the fundamental, and take the energy left in every other
+        // harmonic relative to the fundamental's. This isolates interpolation
+        // error itself rather than going through `synth`, whose fixed sample
+        // rate/frequency stepping would mix spectral leakage from the
+        // windowing into the measurement.
+        let impurity = |wt: &Sine| -> f64 {
+            let w = |k: usize| 2.0 * PI * k as f64 / n_probes as f64;
+            let xs: Vec<f64> = (0..n_probes).map(|k| wt.sample_at(k as f64 / n_probes as f64, 0.0)).collect();
+            let a1 = xs.iter().enumerate().map(|(k, x)| x * w(k).sin()).sum::<f64>() * 2.0 / n_probes as f64;
+            let b1 = xs.iter().enumerate().map(|(k, x)| x * w(k).cos()).sum::<f64>() * 2.0 / n_probes as f64;
+            let residual: f64 = xs
+                .iter()
+                .enumerate()
+                .map(|(k, x)| (x - a1 * w(k).sin() - b1 * w(k).cos()).powi(2))
+                .sum();
+            residual / ((a1 * a1 + b1 * b1) / 2.0 * n_probes as f64)
+        };
+
+        assert!(impurity(&cubic) < impurity(&linear));
     }
 }